@@ -0,0 +1,55 @@
+use std::{
+    collections::HashSet,
+    sync::Mutex,
+    time::Duration,
+};
+
+use serde_json::Value;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+/// How often the backend ticks out a `backend-tick` event while the app is running.
+const TICK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Channel names the frontend has asked to be kept informed about.
+#[derive(Default)]
+pub struct SubscriptionState(Mutex<HashSet<String>>);
+
+/// Spawns the background task that periodically emits `backend-tick` events
+/// once the frontend has subscribed to the `backend-tick` channel, giving it a push channel for
+/// things that aren't a direct reply to a command (progress updates,
+/// notifications, ...).
+pub fn spawn_tick_task<R: Runtime>(app_handle: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(TICK_INTERVAL).await;
+            if is_subscribed(&app_handle, "backend-tick") {
+                let _ = app_handle.emit("backend-tick", serde_json::json!({ "tick": true }));
+            }
+        }
+    });
+}
+
+/// Registers the frontend's interest in a named channel. The tick task checks
+/// `is_subscribed` before emitting; `broadcast` does not consult subscriptions.
+#[tauri::command]
+pub fn subscribe_channel<R: Runtime>(app: AppHandle<R>, name: String) -> Result<(), String> {
+    let state = app.state::<SubscriptionState>();
+    state
+        .0
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(name);
+    Ok(())
+}
+
+/// Whether the frontend has subscribed to the given channel name.
+fn is_subscribed<R: Runtime>(app: &AppHandle<R>, name: &str) -> bool {
+    app.state::<SubscriptionState>().0.lock().unwrap().contains(name)
+}
+
+/// Lets one window relay an event to every window, so commands don't have to
+/// know who else is listening.
+#[tauri::command]
+pub fn broadcast<R: Runtime>(app: AppHandle<R>, event: String, json: Value) -> Result<(), String> {
+    app.emit(&event, json).map_err(|e| e.to_string())
+}
@@ -1,4 +1,10 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
+mod events;
+mod menu;
+mod shutdown;
+mod tray;
+mod windows;
+
 use tauri::Manager;
 
 #[tauri::command]
@@ -6,9 +12,15 @@ fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
+/// Shared exit path for every route that can terminate the app (command,
+/// menu, tray, ...), so they all behave the same way.
+fn graceful_exit<R: tauri::Runtime>(app_handle: &tauri::AppHandle<R>) {
+    app_handle.exit(0);
+}
+
 #[tauri::command]
 async fn force_close_app(app_handle: tauri::AppHandle) -> Result<(), String> {
-    app_handle.exit(0);
+    shutdown::begin_quit(app_handle, shutdown::DEFAULT_QUIT_TIMEOUT).await;
     Ok(())
 }
 
@@ -16,18 +28,53 @@ async fn force_close_app(app_handle: tauri::AppHandle) -> Result<(), String> {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet, force_close_app])
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            force_close_app,
+            menu::set_menu_enabled,
+            events::subscribe_channel,
+            events::broadcast,
+            tray::set_close_behavior,
+            windows::open_window,
+            windows::close_window,
+            windows::focus_window,
+            windows::list_windows,
+            shutdown::request_quit,
+            shutdown::quit_ready
+        ])
+        .manage(events::SubscriptionState::default())
+        .manage(tray::CloseBehaviorState::default())
+        .manage(windows::WindowRegistry::default())
+        .manage(shutdown::ShutdownState::default())
         .setup(|app| {
             let window = app.get_webview_window("main")
                 .ok_or("Failed to get main window")?;
-            
-            // Prevenir cierre con Alt+F4
-            window.on_window_event(|event| {
+
+            let app_handle = app.handle().clone();
+            window.on_window_event(move |event| {
                 if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                    api.prevent_close();
+                    match tray::close_behavior(&app_handle) {
+                        tray::CloseBehavior::Tray => {
+                            api.prevent_close();
+                            tray::hide_main_window(&app_handle);
+                        }
+                        tray::CloseBehavior::Prevent => api.prevent_close(),
+                        tray::CloseBehavior::Exit => {
+                            api.prevent_close();
+                            shutdown::spawn_quit(&app_handle);
+                        }
+                    }
                 }
             });
-            
+
+            let menu = menu::build_menu(app.handle())?;
+            app.set_menu(menu)?;
+            app.on_menu_event(|app_handle, event| menu::handle_menu_event(app_handle, event));
+
+            tray::build_tray(app.handle())?;
+
+            events::spawn_tick_task(app.handle().clone());
+
             Ok::<(), Box<dyn std::error::Error>>(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,74 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use tauri::{
+    menu::{Menu, MenuEvent, MenuItem, MenuItemKind, Submenu},
+    AppHandle, Emitter, Manager, Runtime,
+};
+
+/// Menu items keyed by id. `Menu::get` only searches the menu's top-level
+/// entries, not the submenus our items actually live in, so lookups for
+/// `set_menu_enabled` go through this instead of walking the menu tree.
+pub struct MenuItemsState<R: Runtime>(Mutex<HashMap<String, MenuItemKind<R>>>);
+
+impl<R: Runtime> Default for MenuItemsState<R> {
+    fn default() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+/// Builds the application's native menu bar (File, Help) and registers its
+/// items in managed state so they can be looked up by id later.
+pub fn build_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
+    let new_item = MenuItem::with_id(app, "file-new", "New", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let file_menu = Submenu::with_items(app, "File", true, &[&new_item, &quit_item])?;
+
+    let about_item = MenuItem::with_id(app, "help-about", "About", true, None::<&str>)?;
+    let help_menu = Submenu::with_items(app, "Help", true, &[&about_item])?;
+
+    let menu = Menu::with_items(app, &[&file_menu, &help_menu])?;
+
+    let mut items = HashMap::new();
+    items.insert("file-new".to_string(), MenuItemKind::MenuItem(new_item));
+    items.insert("quit".to_string(), MenuItemKind::MenuItem(quit_item));
+    items.insert("help-about".to_string(), MenuItemKind::MenuItem(about_item));
+    app.manage(MenuItemsState(Mutex::new(items)));
+
+    Ok(menu)
+}
+
+/// Routes menu item ids to their Rust-side handlers.
+pub fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, event: MenuEvent) {
+    match event.id().as_ref() {
+        "quit" => crate::shutdown::spawn_quit(app),
+        "file-new" => {
+            let _ = app.emit("menu-file-new", ());
+        }
+        "help-about" => {
+            let _ = app.emit("menu-help-about", ());
+        }
+        _ => {}
+    }
+}
+
+/// Enables or disables one of the menu items registered by `build_menu`, by
+/// id, for the frontend to toggle availability at runtime. The File and Help
+/// submenus are not registered, so they cannot be toggled by id.
+#[tauri::command]
+pub fn set_menu_enabled<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    enabled: bool,
+) -> Result<(), String> {
+    let state = app.state::<MenuItemsState<R>>();
+    let items = state.0.lock().map_err(|e| e.to_string())?;
+    let item = items
+        .get(&id)
+        .ok_or_else(|| format!("no menu item with id `{id}`"))?;
+
+    match item {
+        MenuItemKind::MenuItem(item) => item.set_enabled(enabled).map_err(|e| e.to_string()),
+        MenuItemKind::Submenu(item) => item.set_enabled(enabled).map_err(|e| e.to_string()),
+        _ => Err(format!("menu item `{id}` does not support enabling/disabling")),
+    }
+}
@@ -0,0 +1,80 @@
+use std::{future::Future, pin::Pin, sync::Mutex, time::Duration};
+
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::sync::oneshot;
+
+type BeforeQuitCallback = Box<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// How long `request_quit` waits for the frontend to call `quit_ready`
+/// before giving up and quitting anyway.
+pub(crate) const DEFAULT_QUIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Default)]
+pub struct ShutdownState {
+    before_quit: Mutex<Vec<BeforeQuitCallback>>,
+    ready_tx: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+/// Registers a Rust-side callback that runs once the frontend has confirmed
+/// it's ready to quit, but before the process actually exits.
+#[allow(dead_code)]
+pub fn register_before_quit<R: Runtime>(
+    app: &AppHandle<R>,
+    callback: impl Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync + 'static,
+) {
+    app.state::<ShutdownState>()
+        .before_quit
+        .lock()
+        .unwrap()
+        .push(Box::new(callback));
+}
+
+/// Runs the full shutdown pipeline: ask the frontend to save state, wait for
+/// its go-ahead (or time out), flush the registered Rust callbacks, then
+/// exit. Every quit route (command, menu, tray, window close) goes through
+/// here so no exit route skips the save-state step.
+pub async fn begin_quit<R: Runtime>(app: AppHandle<R>, timeout: Duration) {
+    let (tx, rx) = oneshot::channel();
+    *app.state::<ShutdownState>().ready_tx.lock().unwrap() = Some(tx);
+
+    let _ = app.emit("before-quit", ());
+    let _ = tokio::time::timeout(timeout, rx).await;
+
+    let callbacks = std::mem::take(&mut *app.state::<ShutdownState>().before_quit.lock().unwrap());
+    for callback in callbacks.iter() {
+        callback().await;
+    }
+
+    crate::graceful_exit(&app);
+}
+
+/// Kicks off the shutdown pipeline from a non-async context (menu item, tray
+/// item, window close handler) without making the caller wait for it.
+pub fn spawn_quit<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(begin_quit(app, DEFAULT_QUIT_TIMEOUT));
+}
+
+/// Starts the shutdown pipeline, optionally overriding the default
+/// save-state timeout (in milliseconds).
+#[tauri::command]
+pub async fn request_quit<R: Runtime>(
+    app: AppHandle<R>,
+    timeout_ms: Option<u64>,
+) -> Result<(), String> {
+    let timeout = timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_QUIT_TIMEOUT);
+    begin_quit(app, timeout).await;
+    Ok(())
+}
+
+/// Called by the frontend once it has finished flushing state, so
+/// `request_quit` can proceed without waiting out the full timeout.
+#[tauri::command]
+pub fn quit_ready<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    if let Some(tx) = app.state::<ShutdownState>().ready_tx.lock().unwrap().take() {
+        let _ = tx.send(());
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+
+use tauri::{
+    menu::{Menu, MenuItem},
+    tray::TrayIconBuilder,
+    AppHandle, Manager, Runtime,
+};
+
+/// What should happen when the user asks the main window to close.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CloseBehavior {
+    /// Hide the window to the tray (default).
+    Tray,
+    /// Let the close go through and exit the app.
+    Exit,
+    /// Block the close entirely, with no escape hatch.
+    Prevent,
+}
+
+impl CloseBehavior {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "tray" => Ok(Self::Tray),
+            "exit" => Ok(Self::Exit),
+            "prevent" => Ok(Self::Prevent),
+            other => Err(format!("unknown close behavior `{other}`")),
+        }
+    }
+}
+
+pub struct CloseBehaviorState(pub Mutex<CloseBehavior>);
+
+impl Default for CloseBehaviorState {
+    fn default() -> Self {
+        Self(Mutex::new(CloseBehavior::Tray))
+    }
+}
+
+/// Builds the tray icon and its Show / Hide / Quit context menu.
+pub fn build_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    let show_item = MenuItem::with_id(app, "tray-show", "Show", true, None::<&str>)?;
+    let hide_item = MenuItem::with_id(app, "tray-hide", "Hide", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "tray-quit", "Quit", true, None::<&str>)?;
+    let tray_menu = Menu::with_items(app, &[&show_item, &hide_item, &quit_item])?;
+
+    let icon = app.default_window_icon().cloned().ok_or_else(|| {
+        tauri::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "no default window icon configured for the tray",
+        ))
+    })?;
+
+    TrayIconBuilder::new()
+        .icon(icon)
+        .menu(&tray_menu)
+        .on_menu_event(|app_handle, event| match event.id().as_ref() {
+            "tray-show" => show_main_window(app_handle),
+            "tray-hide" => hide_main_window(app_handle),
+            "tray-quit" => crate::shutdown::spawn_quit(app_handle),
+            _ => {}
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+pub(crate) fn show_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+pub(crate) fn hide_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.hide();
+    }
+}
+
+/// Reads the close policy the frontend last selected (tray by default).
+pub(crate) fn close_behavior<R: Runtime>(app: &AppHandle<R>) -> CloseBehavior {
+    *app.state::<CloseBehaviorState>().0.lock().unwrap()
+}
+
+/// Lets the frontend pick the close policy at runtime.
+#[tauri::command]
+pub fn set_close_behavior<R: Runtime>(app: AppHandle<R>, behavior: String) -> Result<(), String> {
+    let parsed = CloseBehavior::parse(&behavior)?;
+    *app.state::<CloseBehaviorState>().0.lock().map_err(|e| e.to_string())? = parsed;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+
+use crate::tray;
+
+/// Labels of the windows opened through [`open_window`], so close policy and
+/// lifecycle events can be applied consistently to every one of them.
+#[derive(Default)]
+pub struct WindowRegistry(Mutex<HashSet<String>>);
+
+#[derive(Serialize)]
+pub struct WindowInfo {
+    label: String,
+    visible: bool,
+}
+
+/// Opens a new labeled window pointing at `url`, honoring the current close
+/// policy and emitting `window-closed` when it's destroyed.
+#[tauri::command]
+pub fn open_window<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    url: String,
+    title: String,
+    width: f64,
+    height: f64,
+) -> Result<(), String> {
+    let window = WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(url.into()))
+        .title(title)
+        .inner_size(width, height)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    app.state::<WindowRegistry>()
+        .0
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(label.clone());
+
+    let app_handle = app.clone();
+    let window_label = label.clone();
+    window.on_window_event(move |event| match event {
+        tauri::WindowEvent::CloseRequested { api, .. } => {
+            let tracked = app_handle
+                .state::<WindowRegistry>()
+                .0
+                .lock()
+                .unwrap()
+                .contains(&window_label);
+            if !tracked {
+                return;
+            }
+
+            match tray::close_behavior(&app_handle) {
+                tray::CloseBehavior::Tray => {
+                    api.prevent_close();
+                    if let Some(window) = app_handle.get_webview_window(&window_label) {
+                        let _ = window.hide();
+                    }
+                }
+                tray::CloseBehavior::Prevent => api.prevent_close(),
+                tray::CloseBehavior::Exit => {}
+            }
+        }
+        tauri::WindowEvent::Destroyed => {
+            app_handle
+                .state::<WindowRegistry>()
+                .0
+                .lock()
+                .unwrap()
+                .remove(&window_label);
+            let _ = app_handle.emit("window-closed", &window_label);
+        }
+        _ => {}
+    });
+
+    Ok(())
+}
+
+/// Closes a previously opened window by label.
+#[tauri::command]
+pub fn close_window<R: Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no window with label `{label}`"))?;
+    // Untrack it first so its close handler lets this close through instead
+    // of applying the tray / prevent policy meant for user close requests.
+    app.state::<WindowRegistry>()
+        .0
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&label);
+    window.close().map_err(|e| e.to_string())
+}
+
+/// Brings a window to the front and gives it focus.
+#[tauri::command]
+pub fn focus_window<R: Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("no window with label `{label}`"))?;
+    window.set_focus().map_err(|e| e.to_string())
+}
+
+/// Lists every open window's label and current visibility.
+#[tauri::command]
+pub fn list_windows<R: Runtime>(app: AppHandle<R>) -> Vec<WindowInfo> {
+    app.webview_windows()
+        .into_iter()
+        .map(|(label, window)| WindowInfo {
+            visible: window.is_visible().unwrap_or(false),
+            label,
+        })
+        .collect()
+}